@@ -1,12 +1,79 @@
 use crate::{UnknownTxEnvelope, UnknownTypedTransaction};
-use alloy_consensus::{Transaction as TransactionTrait, TxEnvelope, TypedTransaction};
+use alloy_consensus::{
+    crypto::RecoveryError, transaction::SignerRecoverable, Transaction as TransactionTrait,
+    TxEnvelope, TypedTransaction,
+};
 use alloy_eips::{
-    eip2718::{Decodable2718, Encodable2718},
+    eip2718::{Decodable2718, Eip2718Result, Encodable2718},
     eip7702::SignedAuthorization,
 };
-use alloy_primitives::{Bytes, B256, U256};
+use alloy_primitives::{Address, Bytes, B256, U256};
 use alloy_rpc_types_eth::{AccessList, TransactionRequest};
 use alloy_serde::{OtherFields, WithOtherFields};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+/// A codec for encoding and decoding a single non-Ethereum EIP-2718 transaction type.
+///
+/// Implementations let [`AnyTxEnvelope`] round-trip transaction types that this crate does not
+/// know about natively, by consulting the [`TxCodecRegistry`] registered for the transaction's
+/// type byte.
+pub trait TxCodec: Send + Sync + 'static {
+    /// Encodes the EIP-2718 payload (i.e. everything after the leading type byte) for a
+    /// transaction whose fields are captured in `fields`.
+    fn encode_2718(&self, fields: &OtherFields, out: &mut dyn alloy_primitives::bytes::BufMut);
+
+    /// Returns the length, in bytes, of the EIP-2718 payload that [`TxCodec::encode_2718`] would
+    /// write for `fields`.
+    fn fallback_len(&self, fields: &OtherFields) -> usize;
+
+    /// Decodes an EIP-2718 payload with the given type byte into an [`UnknownTxEnvelope`].
+    fn decode_2718(&self, ty: u8, buf: &mut &[u8]) -> Eip2718Result<UnknownTxEnvelope>;
+}
+
+/// A process-wide registry of [`TxCodec`]s, keyed by EIP-2718 transaction type byte.
+///
+/// [`AnyTxEnvelope`] consults [`TxCodecRegistry::global`] from its `Encodable2718` and
+/// `Decodable2718` impls so that custom networks can make their own transaction types round-trip
+/// through the standard EIP-2718 path, instead of the `Unknown` variant only ever panicking or
+/// being ignored.
+#[derive(Default)]
+pub struct TxCodecRegistry {
+    codecs: RwLock<HashMap<u8, Arc<dyn TxCodec>>>,
+}
+
+impl TxCodecRegistry {
+    /// Returns the process-wide registry instance.
+    pub fn global() -> &'static Self {
+        static REGISTRY: OnceLock<TxCodecRegistry> = OnceLock::new();
+        REGISTRY.get_or_init(Self::default)
+    }
+
+    /// Registers `codec` for `type_byte`, returning the previously registered codec, if any.
+    pub fn register(&self, type_byte: u8, codec: Arc<dyn TxCodec>) -> Option<Arc<dyn TxCodec>> {
+        self.codecs.write().unwrap().insert(type_byte, codec)
+    }
+
+    /// Removes and returns the codec registered for `type_byte`, if any.
+    pub fn deregister(&self, type_byte: u8) -> Option<Arc<dyn TxCodec>> {
+        self.codecs.write().unwrap().remove(&type_byte)
+    }
+
+    /// Returns the codec registered for `type_byte`, if any.
+    pub fn get(&self, type_byte: u8) -> Option<Arc<dyn TxCodec>> {
+        self.codecs.read().unwrap().get(&type_byte).cloned()
+    }
+}
+
+impl fmt::Debug for TxCodecRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let registered: Vec<u8> = self.codecs.read().unwrap().keys().copied().collect();
+        f.debug_struct("TxCodecRegistry").field("registered", &registered).finish()
+    }
+}
 
 /// Unsigned transaction type for a catch-all network.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -35,6 +102,108 @@ impl AnyTypedTransaction {
             Self::Unknown(inner) => inner.deser_by_key(key),
         }
     }
+
+    /// Sets the transaction's nonce.
+    pub fn set_nonce(&mut self, nonce: u64) -> &mut Self {
+        match self {
+            Self::Ethereum(tx) => match tx {
+                TypedTransaction::Legacy(tx) => tx.nonce = nonce,
+                TypedTransaction::Eip2930(tx) => tx.nonce = nonce,
+                TypedTransaction::Eip1559(tx) => tx.nonce = nonce,
+                TypedTransaction::Eip4844(tx) => tx.nonce = nonce,
+                TypedTransaction::Eip7702(tx) => tx.nonce = nonce,
+            },
+            Self::Unknown(inner) => {
+                inner
+                    .fields
+                    .insert("nonce".to_string(), serde_json::Value::String(format!("{nonce:#x}")));
+            }
+        }
+        self
+    }
+
+    /// Sets the transaction's gas limit.
+    pub fn set_gas_limit(&mut self, gas_limit: u64) -> &mut Self {
+        match self {
+            Self::Ethereum(tx) => match tx {
+                TypedTransaction::Legacy(tx) => tx.gas_limit = gas_limit,
+                TypedTransaction::Eip2930(tx) => tx.gas_limit = gas_limit,
+                TypedTransaction::Eip1559(tx) => tx.gas_limit = gas_limit,
+                TypedTransaction::Eip4844(tx) => tx.gas_limit = gas_limit,
+                TypedTransaction::Eip7702(tx) => tx.gas_limit = gas_limit,
+            },
+            Self::Unknown(inner) => {
+                inner.fields.insert(
+                    "gas".to_string(),
+                    serde_json::Value::String(format!("{gas_limit:#x}")),
+                );
+            }
+        }
+        self
+    }
+
+    /// Sets the transaction's chain ID.
+    pub fn set_chain_id(&mut self, chain_id: alloy_primitives::ChainId) -> &mut Self {
+        match self {
+            Self::Ethereum(tx) => match tx {
+                TypedTransaction::Legacy(tx) => tx.chain_id = Some(chain_id),
+                TypedTransaction::Eip2930(tx) => tx.chain_id = chain_id,
+                TypedTransaction::Eip1559(tx) => tx.chain_id = chain_id,
+                TypedTransaction::Eip4844(tx) => tx.chain_id = chain_id,
+                TypedTransaction::Eip7702(tx) => tx.chain_id = chain_id,
+            },
+            Self::Unknown(inner) => {
+                inner.fields.insert(
+                    "chainId".to_string(),
+                    serde_json::Value::String(format!("{chain_id:#x}")),
+                );
+            }
+        }
+        self
+    }
+
+    /// Sets the transaction's access list.
+    ///
+    /// This is a no-op for a legacy Ethereum transaction, which has no access list.
+    pub fn set_access_list(&mut self, access_list: AccessList) -> &mut Self {
+        match self {
+            Self::Ethereum(tx) => match tx {
+                TypedTransaction::Legacy(_) => {}
+                TypedTransaction::Eip2930(tx) => tx.access_list = access_list,
+                TypedTransaction::Eip1559(tx) => tx.access_list = access_list,
+                TypedTransaction::Eip4844(tx) => tx.access_list = access_list,
+                TypedTransaction::Eip7702(tx) => tx.access_list = access_list,
+            },
+            Self::Unknown(inner) => {
+                // `AccessList`'s `Serialize` impl is infallible (it only ever produces a JSON
+                // array of plain structs), so this can't actually fail; we still refuse to treat
+                // a failure as a silent no-op.
+                let value = serde_json::to_value(&access_list)
+                    .expect("AccessList serialization is infallible");
+                inner.fields.insert("accessList".to_string(), value);
+            }
+        }
+        self
+    }
+
+    /// Sets the transaction's input data.
+    pub fn set_input(&mut self, input: Bytes) -> &mut Self {
+        match self {
+            Self::Ethereum(tx) => match tx {
+                TypedTransaction::Legacy(tx) => tx.input = input,
+                TypedTransaction::Eip2930(tx) => tx.input = input,
+                TypedTransaction::Eip1559(tx) => tx.input = input,
+                TypedTransaction::Eip4844(tx) => tx.input = input,
+                TypedTransaction::Eip7702(tx) => tx.input = input,
+            },
+            Self::Unknown(inner) => {
+                inner
+                    .fields
+                    .insert("input".to_string(), serde_json::Value::String(input.to_string()));
+            }
+        }
+        self
+    }
 }
 
 impl From<UnknownTypedTransaction> for AnyTypedTransaction {
@@ -130,6 +299,20 @@ impl TransactionTrait for AnyTypedTransaction {
         self.max_priority_fee_per_gas().or_else(|| self.gas_price()).unwrap_or_default()
     }
 
+    fn effective_gas_price(&self, base_fee: Option<u64>) -> u128 {
+        match self {
+            Self::Ethereum(inner) => inner.effective_gas_price(base_fee),
+            Self::Unknown(inner) => inner.effective_gas_price(base_fee),
+        }
+    }
+
+    fn effective_tip_per_gas(&self, base_fee: u64) -> Option<u128> {
+        match self {
+            Self::Ethereum(inner) => inner.effective_tip_per_gas(base_fee),
+            Self::Unknown(inner) => inner.effective_tip_per_gas(base_fee),
+        }
+    }
+
     fn kind(&self) -> alloy_primitives::TxKind {
         match self {
             Self::Ethereum(inner) => inner.kind(),
@@ -207,6 +390,24 @@ impl AnyTxEnvelope {
             Self::Unknown(inner) => inner.inner.deser_by_key(key),
         }
     }
+
+    /// Recovers the signer of the transaction.
+    ///
+    /// # Support
+    ///
+    /// Only the [`Ethereum`](Self::Ethereum) variant is supported. The [`Unknown`](Self::Unknown)
+    /// variant has no known EIP-2718 encoding for its unsigned form (that is precisely what makes
+    /// it "unknown"), so the hash that its signature was produced over cannot be reconstructed
+    /// generically, and this always returns [`RecoveryError`] for it rather than recovering
+    /// against some other, incorrect hash and silently returning the wrong signer. Networks that
+    /// know how to recover their own unknown transaction types should do so before converting
+    /// into an [`AnyTxEnvelope`].
+    pub fn recover_signer(&self) -> Result<Address, RecoveryError> {
+        match self {
+            Self::Ethereum(tx) => tx.recover_signer(),
+            Self::Unknown(_) => Err(RecoveryError::new()),
+        }
+    }
 }
 
 impl Encodable2718 for AnyTxEnvelope {
@@ -220,7 +421,10 @@ impl Encodable2718 for AnyTxEnvelope {
     fn encode_2718_len(&self) -> usize {
         match self {
             Self::Ethereum(t) => t.encode_2718_len(),
-            Self::Unknown(_) => 1,
+            Self::Unknown(inner) => TxCodecRegistry::global()
+                .get(inner.ty())
+                .map(|codec| 1 + codec.fallback_len(&inner.as_ref().fields))
+                .unwrap_or(1),
         }
     }
 
@@ -228,12 +432,16 @@ impl Encodable2718 for AnyTxEnvelope {
     fn encode_2718(&self, out: &mut dyn alloy_primitives::bytes::BufMut) {
         match self {
             Self::Ethereum(t) => t.encode_2718(out),
-            Self::Unknown(inner) => {
-                panic!(
-                    "Attempted to encode unknown transaction type: {}. This is not a bug in alloy. To encode or decode unknown transaction types, use a custom Transaction type and a custom Network implementation. See https://docs.rs/alloy-network/latest/alloy_network/ for network documentation.",
+            Self::Unknown(inner) => match TxCodecRegistry::global().get(inner.ty()) {
+                Some(codec) => {
+                    out.put_u8(inner.ty());
+                    codec.encode_2718(&inner.as_ref().fields, out);
+                }
+                None => panic!(
+                    "Attempted to encode unknown transaction type: {}. This is not a bug in alloy. To encode or decode unknown transaction types, register a `TxCodec` with `TxCodecRegistry::global()`, or use a custom Transaction type and a custom Network implementation. See https://docs.rs/alloy-network/latest/alloy_network/ for network documentation.",
                     inner.as_ref().ty
-                )
-            }
+                ),
+            },
         }
     }
 
@@ -247,6 +455,9 @@ impl Encodable2718 for AnyTxEnvelope {
 
 impl Decodable2718 for AnyTxEnvelope {
     fn typed_decode(ty: u8, buf: &mut &[u8]) -> alloy_eips::eip2718::Eip2718Result<Self> {
+        if let Some(codec) = TxCodecRegistry::global().get(ty) {
+            return codec.decode_2718(ty, buf).map(Self::Unknown);
+        }
         TxEnvelope::typed_decode(ty, buf).map(Self::Ethereum)
     }
 
@@ -309,6 +520,20 @@ impl TransactionTrait for AnyTxEnvelope {
         self.max_priority_fee_per_gas().or_else(|| self.gas_price()).unwrap_or_default()
     }
 
+    fn effective_gas_price(&self, base_fee: Option<u64>) -> u128 {
+        match self {
+            Self::Ethereum(inner) => inner.effective_gas_price(base_fee),
+            Self::Unknown(inner) => inner.effective_gas_price(base_fee),
+        }
+    }
+
+    fn effective_tip_per_gas(&self, base_fee: u64) -> Option<u128> {
+        match self {
+            Self::Ethereum(inner) => inner.effective_tip_per_gas(base_fee),
+            Self::Unknown(inner) => inner.effective_tip_per_gas(base_fee),
+        }
+    }
+
     fn kind(&self) -> alloy_primitives::TxKind {
         match self {
             Self::Ethereum(inner) => inner.kind(),
@@ -357,4 +582,132 @@ impl TransactionTrait for AnyTxEnvelope {
             Self::Unknown(inner) => inner.authorization_list(),
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::TxEip1559;
+    use alloy_primitives::keccak256;
+    use std::sync::Arc;
+
+    /// A [`TxCodec`] that round-trips its payload verbatim, used only to exercise dispatch
+    /// through [`TxCodecRegistry`].
+    struct EchoCodec;
+
+    impl TxCodec for EchoCodec {
+        fn encode_2718(&self, fields: &OtherFields, out: &mut dyn alloy_primitives::bytes::BufMut) {
+            if let Some(payload) = fields.get("payload").and_then(|v| v.as_str()) {
+                out.put_slice(payload.as_bytes());
+            }
+        }
+
+        fn fallback_len(&self, fields: &OtherFields) -> usize {
+            fields.get("payload").and_then(|v| v.as_str()).map(str::len).unwrap_or(0)
+        }
+
+        fn decode_2718(&self, ty: u8, buf: &mut &[u8]) -> Eip2718Result<UnknownTxEnvelope> {
+            let payload = core::str::from_utf8(buf).expect("test payload is utf8").to_string();
+            *buf = &buf[buf.len()..];
+
+            let value = serde_json::json!({
+                "type": format!("{ty:#x}"),
+                "hash": keccak256(payload.as_bytes()),
+                "payload": payload,
+            });
+            match serde_json::from_value(value).expect("valid unknown tx json") {
+                AnyTxEnvelope::Unknown(inner) => Ok(inner),
+                AnyTxEnvelope::Ethereum(_) => unreachable!("test type byte is not Ethereum"),
+            }
+        }
+    }
+
+    #[test]
+    fn codec_registry_round_trips_an_unknown_tx_type() {
+        // A type byte well outside the range of any Ethereum tx type.
+        const TY: u8 = 0x7f;
+
+        TxCodecRegistry::global().register(TY, Arc::new(EchoCodec));
+
+        let value = serde_json::json!({
+            "type": format!("{TY:#x}"),
+            "hash": keccak256(b"hello"),
+            "payload": "hello",
+        });
+        let original: AnyTxEnvelope = serde_json::from_value(value).unwrap();
+
+        let mut encoded = Vec::new();
+        original.encode_2718(&mut encoded);
+        assert_eq!(encoded.len(), original.encode_2718_len());
+        assert_eq!(encoded[0], TY);
+
+        let decoded = AnyTxEnvelope::decode_2718(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded.ty(), TY);
+
+        TxCodecRegistry::global().deregister(TY);
+    }
+
+    fn eip1559(max_fee_per_gas: u128, max_priority_fee_per_gas: u128) -> AnyTypedTransaction {
+        AnyTypedTransaction::Ethereum(TypedTransaction::Eip1559(TxEip1559 {
+            chain_id: 1,
+            nonce: 0,
+            gas_limit: 21_000,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            to: alloy_primitives::TxKind::Create,
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            input: Bytes::new(),
+        }))
+    }
+
+    #[test]
+    fn effective_gas_price_caps_at_max_fee_per_gas() {
+        let tx = eip1559(100, 10);
+        assert_eq!(tx.effective_gas_price(Some(50)), 60);
+        assert_eq!(tx.effective_gas_price(Some(1_000)), 100);
+    }
+
+    #[test]
+    fn effective_tip_per_gas_is_none_when_base_fee_exceeds_max_fee() {
+        let tx = eip1559(100, 10);
+        assert_eq!(tx.effective_tip_per_gas(50), Some(10));
+        assert_eq!(tx.effective_tip_per_gas(95), Some(5));
+        assert_eq!(tx.effective_tip_per_gas(200), None);
+    }
+
+    #[test]
+    fn setters_mutate_the_ethereum_variant_in_place() {
+        let mut tx = eip1559(100, 10);
+
+        tx.set_nonce(7);
+        tx.set_gas_limit(30_000);
+        tx.set_chain_id(5);
+        tx.set_input(Bytes::from_static(b"abc"));
+
+        assert_eq!(tx.nonce(), 7);
+        assert_eq!(tx.gas_limit(), 30_000);
+        assert_eq!(tx.chain_id(), Some(5));
+        assert_eq!(tx.input().as_ref(), b"abc");
+    }
+
+    #[test]
+    fn setters_write_canonical_rpc_keys_for_the_unknown_variant() {
+        let value = serde_json::json!({
+            "type": "0x7d",
+            "nonce": "0x0",
+        });
+        let mut tx: AnyTypedTransaction = serde_json::from_value(value).unwrap();
+
+        tx.set_nonce(9);
+        tx.set_gas_limit(21_000);
+        tx.set_chain_id(5);
+
+        let AnyTypedTransaction::Unknown(inner) = &tx else {
+            panic!("expected the Unknown variant");
+        };
+        assert_eq!(inner.fields.get("nonce").and_then(|v| v.as_str()), Some("0x9"));
+        assert_eq!(inner.fields.get("gas").and_then(|v| v.as_str()), Some("0x5208"));
+        assert_eq!(inner.fields.get("chainId").and_then(|v| v.as_str()), Some("0x5"));
+    }
+}
@@ -3,7 +3,11 @@
 
 use alloc::vec::Vec;
 
-use alloy_eips::eip7685::Requests;
+use alloy_consensus::TxEnvelope;
+use alloy_eips::{
+    eip2718::{Decodable2718, Eip2718Error},
+    eip7685::Requests,
+};
 
 /// Fields introduced in `engine_newPayloadV4` that are not present in the `ExecutionPayload` RPC
 /// object.
@@ -17,6 +21,26 @@ pub struct PraguePayloadFields {
     pub il: Vec<Vec<u8>>,
 }
 
+impl PraguePayloadFields {
+    /// Decodes the inclusion list into its constituent transactions.
+    ///
+    /// Each entry in [`Self::il`] is treated as an EIP-2718 encoded transaction envelope and
+    /// decoded via [`TxEnvelope::decode_2718`].
+    ///
+    /// This intentionally decodes into the Ethereum [`TxEnvelope`] rather than a catch-all
+    /// "any network" envelope: this crate is `no_std` + `alloc` and sits below
+    /// `alloy-network` in the dependency graph, so it cannot depend on that crate's
+    /// network-agnostic transaction type without both breaking `no_std` support (that type
+    /// pulls in a `std`-only codec registry) and inverting crate layering. The consequence is
+    /// that an inclusion list entry using a non-Ethereum transaction type is not decodable here
+    /// and this returns [`Eip2718Error`] for it; callers on a custom network that needs to
+    /// inspect such entries should decode the raw bytes in [`Self::il`] themselves using their
+    /// network's own transaction type.
+    pub fn il_transactions(&self) -> Result<Vec<TxEnvelope>, Eip2718Error> {
+        self.il.iter().map(|tx| TxEnvelope::decode_2718(&mut tx.as_slice())).collect()
+    }
+}
+
 /// A container type for [PraguePayloadFields] that may or may not be present.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -49,6 +73,13 @@ impl MaybePraguePayloadFields {
     pub const fn as_ref(&self) -> Option<&PraguePayloadFields> {
         self.fields.as_ref()
     }
+
+    /// Decodes the inclusion list into its constituent transactions, if present.
+    ///
+    /// See [`PraguePayloadFields::il_transactions`] for the Ethereum-only scope of this decode.
+    pub fn il_transactions(&self) -> Option<Result<Vec<TxEnvelope>, Eip2718Error>> {
+        self.fields.as_ref().map(PraguePayloadFields::il_transactions)
+    }
 }
 
 impl From<PraguePayloadFields> for MaybePraguePayloadFields {